@@ -0,0 +1,396 @@
+// Code generated by software.amazon.smithy.rust.codegen.smithy-rs. DO NOT EDIT.
+/// Service register input structure
+#[non_exhaustive]
+#[derive(std::clone::Clone, std::cmp::PartialEq)]
+pub struct RegisterServiceInput {
+    /// Id of the service that will be registered
+    pub id: std::option::Option<std::string::String>,
+    /// Name of the service that will be registered
+    pub name: std::option::Option<std::string::String>,
+}
+impl RegisterServiceInput {
+    /// Id of the service that will be registered
+    pub fn id(&self) -> std::option::Option<&str> {
+        self.id.as_deref()
+    }
+    /// Name of the service that will be registered
+    pub fn name(&self) -> std::option::Option<&str> {
+        self.name.as_deref()
+    }
+}
+impl std::fmt::Debug for RegisterServiceInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut formatter = f.debug_struct("RegisterServiceInput");
+        formatter.field("id", &self.id);
+        formatter.field("name", &self.name);
+        formatter.finish()
+    }
+}
+/// See [`RegisterServiceInput`](crate::input::RegisterServiceInput)
+pub mod register_service_input {
+    /// A builder for [`RegisterServiceInput`](crate::input::RegisterServiceInput)
+    #[non_exhaustive]
+    #[derive(std::default::Default, std::clone::Clone, std::cmp::PartialEq, std::fmt::Debug)]
+    pub struct Builder {
+        pub(crate) id: std::option::Option<std::string::String>,
+        pub(crate) name: std::option::Option<std::string::String>,
+    }
+    impl Builder {
+        /// Id of the service that will be registered
+        pub fn id(mut self, input: impl Into<std::string::String>) -> Self {
+            self.id = Some(input.into());
+            self
+        }
+        /// Id of the service that will be registered
+        pub fn set_id(mut self, input: std::option::Option<std::string::String>) -> Self {
+            self.id = input;
+            self
+        }
+        /// Name of the service that will be registered
+        pub fn name(mut self, input: impl Into<std::string::String>) -> Self {
+            self.name = Some(input.into());
+            self
+        }
+        /// Name of the service that will be registered
+        pub fn set_name(mut self, input: std::option::Option<std::string::String>) -> Self {
+            self.name = input;
+            self
+        }
+        /// Consumes the builder and constructs a [`RegisterServiceInput`](crate::input::RegisterServiceInput)
+        pub fn build(
+            self,
+        ) -> std::result::Result<
+            crate::input::RegisterServiceInput,
+            aws_smithy_http::operation::BuildError,
+        > {
+            Ok(crate::input::RegisterServiceInput {
+                id: self.id,
+                name: self.name,
+            })
+        }
+    }
+}
+impl RegisterServiceInput {
+    /// Creates a new builder-style object to manufacture [`RegisterServiceInput`](crate::input::RegisterServiceInput)
+    pub fn builder() -> crate::input::register_service_input::Builder {
+        crate::input::register_service_input::Builder::default()
+    }
+}
+impl RegisterServiceInput {
+    /// Constructs an Operation<[`RegisterService`](crate::operation::RegisterService)>
+    #[allow(clippy::let_and_return)]
+    pub async fn make_operation(
+        &self,
+        _config: &crate::config::Config,
+    ) -> std::result::Result<
+        aws_smithy_http::operation::Operation<
+            crate::operation::RegisterService,
+            aws_http::retry::AwsResponseRetryClassifier,
+        >,
+        aws_smithy_http::operation::BuildError,
+    > {
+        let request = {
+            fn uri_base(
+                _input: &RegisterServiceInput,
+                output: &mut String,
+            ) -> std::result::Result<(), aws_smithy_http::operation::BuildError> {
+                use std::fmt::Write as _;
+                write!(output, "/service").expect("formatting should succeed");
+                Ok(())
+            }
+            let mut uri = String::new();
+            uri_base(self, &mut uri)?;
+            http::request::Builder::new().method("POST").uri(uri)
+        };
+        let body = crate::operation_ser::serialize_operation_crate_operation_register_service(self)?;
+        let request = request
+            .header("content-type", "application/json")
+            .body(aws_smithy_http::body::SdkBody::from(body))
+            .expect("valid request");
+        let mut request = aws_smithy_http::operation::Request::new(request);
+        aws_endpoint::set_endpoint_resolver(
+            &mut request.properties_mut(),
+            _config.endpoint_resolver.clone(),
+        );
+        if let Some(region) = &_config.region {
+            request.properties_mut().insert(region.clone());
+        }
+        aws_http::auth::set_provider(
+            &mut request.properties_mut(),
+            _config.credentials_provider.clone(),
+        );
+        let op = aws_smithy_http::operation::Operation::new(
+            request,
+            crate::operation::RegisterService::new(),
+        )
+        .with_metadata(aws_smithy_http::operation::Metadata::new(
+            "RegisterService",
+            "SimpleService",
+        ))
+        .with_retry_classifier(aws_http::retry::AwsResponseRetryClassifier::new());
+        Ok(op)
+    }
+}
+
+/// Service tag input structure
+#[non_exhaustive]
+#[derive(std::clone::Clone, std::cmp::PartialEq)]
+pub struct TagServiceInput {
+    /// Id of the service being tagged
+    pub id: std::option::Option<std::string::String>,
+    /// Tags to attach to the service
+    pub tags: std::option::Option<
+        std::collections::HashMap<std::string::String, std::string::String>,
+    >,
+    /// Additional aliases for the service
+    pub aliases: std::option::Option<std::vec::Vec<std::string::String>>,
+}
+impl TagServiceInput {
+    /// Id of the service being tagged
+    pub fn id(&self) -> std::option::Option<&str> {
+        self.id.as_deref()
+    }
+    /// Tags to attach to the service
+    pub fn tags(
+        &self,
+    ) -> std::option::Option<&std::collections::HashMap<std::string::String, std::string::String>>
+    {
+        self.tags.as_ref()
+    }
+    /// Additional aliases for the service
+    pub fn aliases(&self) -> std::option::Option<&[std::string::String]> {
+        self.aliases.as_deref()
+    }
+}
+impl std::fmt::Debug for TagServiceInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut formatter = f.debug_struct("TagServiceInput");
+        formatter.field("id", &self.id);
+        formatter.field("tags", &self.tags);
+        formatter.field("aliases", &self.aliases);
+        formatter.finish()
+    }
+}
+/// See [`TagServiceInput`](crate::input::TagServiceInput)
+pub mod tag_service_input {
+    /// A builder for [`TagServiceInput`](crate::input::TagServiceInput)
+    #[non_exhaustive]
+    #[derive(std::default::Default, std::clone::Clone, std::cmp::PartialEq, std::fmt::Debug)]
+    pub struct Builder {
+        pub(crate) id: std::option::Option<std::string::String>,
+        pub(crate) tags: std::option::Option<
+            std::collections::HashMap<std::string::String, std::string::String>,
+        >,
+        pub(crate) aliases: std::option::Option<std::vec::Vec<std::string::String>>,
+    }
+    impl Builder {
+        /// Id of the service being tagged
+        pub fn id(mut self, input: impl Into<std::string::String>) -> Self {
+            self.id = Some(input.into());
+            self
+        }
+        /// Id of the service being tagged
+        pub fn set_id(mut self, input: std::option::Option<std::string::String>) -> Self {
+            self.id = input;
+            self
+        }
+        /// Adds a key-value pair to `tags`.
+        pub fn tags(
+            mut self,
+            k: impl Into<std::string::String>,
+            v: impl Into<std::string::String>,
+        ) -> Self {
+            let mut hash_map = self.tags.unwrap_or_default();
+            hash_map.insert(k.into(), v.into());
+            self.tags = Some(hash_map);
+            self
+        }
+        /// Tags to attach to the service
+        pub fn set_tags(
+            mut self,
+            input: std::option::Option<
+                std::collections::HashMap<std::string::String, std::string::String>,
+            >,
+        ) -> Self {
+            self.tags = input;
+            self
+        }
+        /// Appends an item to `aliases`.
+        pub fn aliases(mut self, input: impl Into<std::string::String>) -> Self {
+            let mut v = self.aliases.unwrap_or_default();
+            v.push(input.into());
+            self.aliases = Some(v);
+            self
+        }
+        /// Additional aliases for the service
+        pub fn set_aliases(
+            mut self,
+            input: std::option::Option<std::vec::Vec<std::string::String>>,
+        ) -> Self {
+            self.aliases = input;
+            self
+        }
+        /// Consumes the builder and constructs a [`TagServiceInput`](crate::input::TagServiceInput)
+        pub fn build(
+            self,
+        ) -> std::result::Result<
+            crate::input::TagServiceInput,
+            aws_smithy_http::operation::BuildError,
+        > {
+            Ok(crate::input::TagServiceInput {
+                id: self.id,
+                tags: self.tags,
+                aliases: self.aliases,
+            })
+        }
+    }
+}
+impl TagServiceInput {
+    /// Creates a new builder-style object to manufacture [`TagServiceInput`](crate::input::TagServiceInput)
+    pub fn builder() -> crate::input::tag_service_input::Builder {
+        crate::input::tag_service_input::Builder::default()
+    }
+}
+impl TagServiceInput {
+    /// Constructs an Operation<[`TagService`](crate::operation::TagService)>
+    #[allow(clippy::let_and_return)]
+    pub async fn make_operation(
+        &self,
+        _config: &crate::config::Config,
+    ) -> std::result::Result<
+        aws_smithy_http::operation::Operation<
+            crate::operation::TagService,
+            aws_http::retry::AwsResponseRetryClassifier,
+        >,
+        aws_smithy_http::operation::BuildError,
+    > {
+        let request = {
+            fn uri_base(
+                _input: &TagServiceInput,
+                output: &mut String,
+            ) -> std::result::Result<(), aws_smithy_http::operation::BuildError> {
+                use std::fmt::Write as _;
+                write!(output, "/service/tags").expect("formatting should succeed");
+                Ok(())
+            }
+            let mut uri = String::new();
+            uri_base(self, &mut uri)?;
+            http::request::Builder::new().method("POST").uri(uri)
+        };
+        let body = crate::operation_ser::serialize_operation_crate_operation_tag_service(self)?;
+        let request = request
+            .header("content-type", "application/json")
+            .body(aws_smithy_http::body::SdkBody::from(body))
+            .expect("valid request");
+        let mut request = aws_smithy_http::operation::Request::new(request);
+        aws_endpoint::set_endpoint_resolver(
+            &mut request.properties_mut(),
+            _config.endpoint_resolver.clone(),
+        );
+        if let Some(region) = &_config.region {
+            request.properties_mut().insert(region.clone());
+        }
+        aws_http::auth::set_provider(
+            &mut request.properties_mut(),
+            _config.credentials_provider.clone(),
+        );
+        let op = aws_smithy_http::operation::Operation::new(
+            request,
+            crate::operation::TagService::new(),
+        )
+        .with_metadata(aws_smithy_http::operation::Metadata::new(
+            "TagService",
+            "SimpleService",
+        ))
+        .with_retry_classifier(aws_http::retry::AwsResponseRetryClassifier::new());
+        Ok(op)
+    }
+}
+
+/// Service healthcheck input structure
+#[non_exhaustive]
+#[derive(std::clone::Clone, std::cmp::PartialEq)]
+pub struct HealthcheckInput {}
+impl std::fmt::Debug for HealthcheckInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut formatter = f.debug_struct("HealthcheckInput");
+        formatter.finish()
+    }
+}
+/// See [`HealthcheckInput`](crate::input::HealthcheckInput)
+pub mod healthcheck_input {
+    /// A builder for [`HealthcheckInput`](crate::input::HealthcheckInput)
+    #[non_exhaustive]
+    #[derive(std::default::Default, std::clone::Clone, std::cmp::PartialEq, std::fmt::Debug)]
+    pub struct Builder {}
+    impl Builder {
+        /// Consumes the builder and constructs a [`HealthcheckInput`](crate::input::HealthcheckInput)
+        pub fn build(
+            self,
+        ) -> std::result::Result<
+            crate::input::HealthcheckInput,
+            aws_smithy_http::operation::BuildError,
+        > {
+            Ok(crate::input::HealthcheckInput {})
+        }
+    }
+}
+impl HealthcheckInput {
+    /// Creates a new builder-style object to manufacture [`HealthcheckInput`](crate::input::HealthcheckInput)
+    pub fn builder() -> crate::input::healthcheck_input::Builder {
+        crate::input::healthcheck_input::Builder::default()
+    }
+}
+impl HealthcheckInput {
+    /// Constructs an Operation<[`Healthcheck`](crate::operation::Healthcheck)>
+    #[allow(clippy::let_and_return)]
+    pub async fn make_operation(
+        &self,
+        _config: &crate::config::Config,
+    ) -> std::result::Result<
+        aws_smithy_http::operation::Operation<
+            crate::operation::Healthcheck,
+            aws_http::retry::AwsResponseRetryClassifier,
+        >,
+        aws_smithy_http::operation::BuildError,
+    > {
+        let request = {
+            fn uri_base(
+                _input: &HealthcheckInput,
+                output: &mut String,
+            ) -> std::result::Result<(), aws_smithy_http::operation::BuildError> {
+                use std::fmt::Write as _;
+                write!(output, "/healthcheck").expect("formatting should succeed");
+                Ok(())
+            }
+            let mut uri = String::new();
+            uri_base(self, &mut uri)?;
+            http::request::Builder::new().method("GET").uri(uri)
+        };
+        let request = request
+            .body(aws_smithy_http::body::SdkBody::empty())
+            .expect("valid request");
+        let mut request = aws_smithy_http::operation::Request::new(request);
+        aws_endpoint::set_endpoint_resolver(
+            &mut request.properties_mut(),
+            _config.endpoint_resolver.clone(),
+        );
+        if let Some(region) = &_config.region {
+            request.properties_mut().insert(region.clone());
+        }
+        aws_http::auth::set_provider(
+            &mut request.properties_mut(),
+            _config.credentials_provider.clone(),
+        );
+        let op = aws_smithy_http::operation::Operation::new(
+            request,
+            crate::operation::Healthcheck::new(),
+        )
+        .with_metadata(aws_smithy_http::operation::Metadata::new(
+            "Healthcheck",
+            "SimpleService",
+        ))
+        .with_retry_classifier(aws_http::retry::AwsResponseRetryClassifier::new());
+        Ok(op)
+    }
+}
@@ -0,0 +1,92 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0.
+ */
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use smithy_types::Instant;
+
+pub struct InstantDateTime(pub Instant);
+
+impl Serialize for InstantDateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        if self.0.has_nanos() {
+            serializer.serialize_str(&self.0.to_iso_8601())
+        } else {
+            serializer.serialize_str(&self.0.to_iso_8601_no_fractional())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for InstantDateTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = <&str>::deserialize(deserializer)?;
+        // Lenient parsing: accept `date-time` values with or without a fractional-second
+        // component and with or without an explicit UTC offset, since both are observed
+        // in the wild from services that don't perfectly follow RFC 3339.
+        Instant::from_str_lenient(value)
+            .map(InstantDateTime)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn serializes_with_fractional_seconds_when_present() {
+        let instant = InstantDateTime(Instant::from_f64(1445412480.5));
+        assert_eq!(
+            serde_json::to_string(&instant).expect("serializes"),
+            format!("\"{}\"", instant.0.to_iso_8601())
+        );
+    }
+
+    #[test]
+    fn serializes_without_fractional_seconds_when_absent() {
+        let instant = InstantDateTime(Instant::from_f64(1445412480.0));
+        assert_eq!(
+            serde_json::to_string(&instant).expect("serializes"),
+            format!("\"{}\"", instant.0.to_iso_8601_no_fractional())
+        );
+    }
+
+    #[test]
+    fn round_trips_with_and_without_fractional_seconds() {
+        for secs in [1445412480.0, 1445412480.125] {
+            let original = InstantDateTime(Instant::from_f64(secs));
+            let serialized = serde_json::to_string(&original).expect("serializes");
+            let deserialized: InstantDateTime =
+                serde_json::from_str(&serialized).expect("deserializes");
+            assert_eq!(
+                serialized,
+                serde_json::to_string(&deserialized).expect("re-serializes")
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_is_lenient_about_fractional_seconds_and_offset() {
+        for value in [
+            "\"2015-10-21T07:28:00Z\"",
+            "\"2015-10-21T07:28:00.5Z\"",
+            "\"2015-10-21T07:28:00+00:00\"",
+        ] {
+            serde_json::from_str::<InstantDateTime>(value)
+                .unwrap_or_else(|e| panic!("expected `{}` to parse leniently: {}", value, e));
+        }
+    }
+
+    #[test]
+    fn deserialize_rejects_malformed_input() {
+        serde_json::from_str::<InstantDateTime>("\"not a date-time\"")
+            .expect_err("malformed date-time should not parse");
+    }
+}
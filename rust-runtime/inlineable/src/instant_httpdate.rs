@@ -0,0 +1,75 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0.
+ */
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use smithy_types::Instant;
+
+pub struct InstantHttpDate(pub Instant);
+
+impl Serialize for InstantHttpDate {
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0.to_http_date())
+    }
+}
+
+impl<'de> Deserialize<'de> for InstantHttpDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = <&str>::deserialize(deserializer)?;
+        Instant::from_http_date(value)
+            .map(InstantHttpDate)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn formats_as_imf_fixdate() {
+        let instant = InstantHttpDate(Instant::from_f64(784111777.0));
+        assert_eq!(
+            serde_json::to_string(&instant).expect("serializes"),
+            format!("\"{}\"", instant.0.to_http_date())
+        );
+    }
+
+    #[test]
+    fn formats_instant_with_nanos_by_truncating_to_whole_seconds() {
+        // `http-date` has no fractional-second component, so an Instant with
+        // nanos formats the same as the instant at its whole second.
+        let instant = InstantHttpDate(Instant::from_f64(784111777.5));
+        assert_eq!(
+            serde_json::to_string(&instant).expect("serializes"),
+            format!("\"{}\"", Instant::from_f64(784111777.0).to_http_date())
+        );
+    }
+
+    #[test]
+    fn round_trips_with_and_without_nanos() {
+        for secs in [784111777.0, 784111777.5] {
+            let original = InstantHttpDate(Instant::from_f64(secs));
+            let serialized = serde_json::to_string(&original).expect("serializes");
+            let deserialized: InstantHttpDate =
+                serde_json::from_str(&serialized).expect("deserializes");
+            assert_eq!(
+                serialized,
+                serde_json::to_string(&deserialized).expect("re-serializes")
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_rejects_malformed_input() {
+        serde_json::from_str::<InstantHttpDate>("\"not a http-date\"")
+            .expect_err("malformed http-date should not parse");
+    }
+}
@@ -4,18 +4,18 @@
 #[derive(std::clone::Clone, std::cmp::PartialEq)]
 pub struct RegisterServiceInput {
     /// Id of the service that will be registered
-    pub id: std::option::Option<std::string::String>,
+    pub id: std::string::String,
     /// Name of the service that will be registered
-    pub name: std::option::Option<std::string::String>,
+    pub name: std::string::String,
 }
 impl RegisterServiceInput {
     /// Id of the service that will be registered
-    pub fn id(&self) -> std::option::Option<&str> {
-        self.id.as_deref()
+    pub fn id(&self) -> &str {
+        &self.id
     }
     /// Name of the service that will be registered
-    pub fn name(&self) -> std::option::Option<&str> {
-        self.name.as_deref()
+    pub fn name(&self) -> &str {
+        &self.name
     }
 }
 impl std::fmt::Debug for RegisterServiceInput {
@@ -64,8 +64,14 @@ pub mod register_service_input {
             aws_smithy_http::operation::BuildError,
         > {
             Ok(crate::input::RegisterServiceInput {
-                id: self.id,
-                name: self.name,
+                id: self.id.ok_or(aws_smithy_http::operation::BuildError::MissingField {
+                    field: "id",
+                    details: "id was not specified but it is required when building RegisterServiceInput",
+                })?,
+                name: self.name.ok_or(aws_smithy_http::operation::BuildError::MissingField {
+                    field: "name",
+                    details: "name was not specified but it is required when building RegisterServiceInput",
+                })?,
             })
         }
     }
@@ -110,4 +116,4 @@ impl HealthcheckInput {
     pub fn builder() -> crate::input::healthcheck_input::Builder {
         crate::input::healthcheck_input::Builder::default()
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,42 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0.
+ */
+
+mod validate;
+
+use anyhow::Result;
+use semver::Version;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use validate::{validate_after_fixes, validate_before_fixes, validate_before_fixes_with_policy, VersionPolicy};
+
+/// Arguments for the `fix-manifests` subcommand.
+pub struct FixManifestsArgs {
+    /// Path to the workspace whose manifests should be fixed
+    pub location: PathBuf,
+    /// Optional path to a version policy manifest (see [`VersionPolicy`]) that lets
+    /// crates be released on independently versioned tracks instead of requiring
+    /// strict lockstep versioning across the whole repo.
+    pub version_policy: Option<PathBuf>,
+}
+
+/// Entry point for the `fix-manifests` subcommand.
+pub(crate) async fn run(args: &FixManifestsArgs, versions: &BTreeMap<String, Version>) -> Result<()> {
+    pre_validate(&args.version_policy, versions)?;
+
+    // No manifest is rewritten here: only the pre- and post-fix validations run.
+
+    validate_after_fixes(&args.location).await?;
+    Ok(())
+}
+
+fn pre_validate(version_policy: &Option<PathBuf>, versions: &BTreeMap<String, Version>) -> Result<()> {
+    match version_policy {
+        Some(policy_path) => {
+            let policy = VersionPolicy::load(policy_path)?;
+            validate_before_fixes_with_policy(versions, &policy)
+        }
+        None => validate_before_fixes(versions),
+    }
+}
@@ -5,8 +5,9 @@
 
 use crate::fs::Fs;
 use crate::package::{discover_and_validate_package_batches, PackageCategory};
-use anyhow::{anyhow, bail, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use semver::Version;
+use serde::Deserialize;
 use std::collections::BTreeMap;
 use std::path::Path;
 use tracing::info;
@@ -38,6 +39,96 @@ pub(super) fn validate_before_fixes(versions: &BTreeMap<String, Version>) -> Res
     Ok(())
 }
 
+/// Independent version tracks for crates, loaded from a version policy manifest.
+///
+/// Crates on the same track must stay semver-compatible with that track's baseline
+/// crate, but crates on different tracks are free to version independently (e.g.
+/// `aws-config` iterating on a stable `1.x` line while service crates are still `0.x`).
+#[derive(Debug, Deserialize)]
+pub(super) struct VersionPolicy {
+    /// Maps a track name (e.g. "runtime", "sdk") to the crate whose version is
+    /// that track's baseline.
+    baselines: BTreeMap<String, String>,
+    /// Maps a crate name to the track it belongs to. A crate missing from this map
+    /// is only checked if it's `aws-smithy-*` (which stays on the built-in
+    /// `smithy-runtime` track by default); individual `aws-sdk-*`/`aws-` service
+    /// crates are otherwise left unconstrained unless explicitly opted into a track,
+    /// since they're expected to version independently of `aws-config` and of
+    /// each other.
+    #[serde(default)]
+    tracks: BTreeMap<String, String>,
+}
+
+impl VersionPolicy {
+    /// Loads a version policy manifest from `path`.
+    pub(super) fn load(path: &Path) -> Result<VersionPolicy> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read version policy from {:?}", path))?;
+        toml::from_str(&contents)
+            .with_context(|| format!("failed to parse version policy from {:?}", path))
+    }
+
+    fn track_for(&self, category: PackageCategory, name: &str) -> Option<&str> {
+        if let Some(track) = self.tracks.get(name) {
+            return Some(track);
+        }
+        if category == PackageCategory::SmithyRuntime {
+            Some("smithy-runtime")
+        } else {
+            None
+        }
+    }
+}
+
+/// Like [`validate_before_fixes`], but instead of enforcing exact lockstep versioning
+/// across the whole repo, groups crates into independent version tracks (as declared
+/// by `policy`) and only requires semver-compatibility within a track.
+pub(super) fn validate_before_fixes_with_policy(
+    versions: &BTreeMap<String, Version>,
+    policy: &VersionPolicy,
+) -> Result<()> {
+    info!("Pre-validating manifests against version policy...");
+    let mut baselines = BTreeMap::new();
+    for (track, crate_name) in &policy.baselines {
+        if let Some(version) = versions.get(crate_name) {
+            baselines.insert(track.as_str(), version);
+        }
+    }
+    for (name, version) in versions {
+        let category = PackageCategory::from_package_name(name);
+        if let Some(track) = policy.track_for(category, name) {
+            if let Some(baseline) = baselines.get(track) {
+                confirm_compatible(name, baseline, version)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Confirms `actual` is semver-compatible with `expected` rather than identical to it,
+/// matching the caret (`^`) compatibility rule semver itself uses for dependency ranges:
+/// same major (or, below `1.0.0`, same minor) AND `actual >= expected`, so a crate that
+/// merely shares a major/minor with the baseline but has fallen behind it is still flagged.
+fn confirm_compatible(name: &str, expected: &Version, actual: &Version) -> Result<()> {
+    let same_compatible_range = if expected.major > 0 {
+        actual.major == expected.major
+    } else if expected.minor > 0 {
+        actual.major == 0 && actual.minor == expected.minor
+    } else {
+        actual.major == 0 && actual.minor == 0 && actual.patch == expected.patch
+    };
+    let compatible = same_compatible_range && actual >= expected;
+    if !compatible {
+        bail!(
+            "Crate named `{}` is at version `{}`, which is not semver-compatible with its track's baseline version `{}`",
+            name,
+            actual,
+            expected
+        );
+    }
+    Ok(())
+}
+
 fn confirm_version(name: &str, expected: &Version, actual: &Version) -> Result<()> {
     if expected != actual {
         bail!(
@@ -140,4 +231,139 @@ mod test {
             ],
         );
     }
+
+    fn policy(baselines: &[(&str, &str)], tracks: &[(&str, &str)]) -> VersionPolicy {
+        VersionPolicy {
+            baselines: baselines
+                .iter()
+                .map(|(track, krate)| (track.to_string(), krate.to_string()))
+                .collect(),
+            tracks: tracks
+                .iter()
+                .map(|(krate, track)| (krate.to_string(), track.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn pre_validate_with_decoupled_policy() {
+        // `aws-config` on a stable 1.x line while individual `aws-sdk-*` service
+        // crates are still on independent 0.x lines should be allowed: unmapped
+        // service crates aren't held to `aws-config`'s track at all, only the
+        // built-in `smithy-runtime` track is still checked by default.
+        let decoupled = policy(&[("smithy-runtime", "aws-smithy-types")], &[]);
+        validate_before_fixes_with_policy(
+            &versions(&[
+                ("aws-config", "1.5.2"),
+                ("aws-sdk-dynamodb", "0.5.1"),
+                ("aws-smithy-types", "0.35.1"),
+                ("aws-smithy-http", "0.35.1"),
+            ]),
+            &decoupled,
+        )
+        .expect("unmapped sdk service crates are independent of aws-config by default");
+
+        let err = validate_before_fixes_with_policy(
+            &versions(&[
+                ("aws-smithy-types", "0.35.1"),
+                ("aws-smithy-http", "0.36.0"),
+            ]),
+            &decoupled,
+        )
+        .expect_err("minor version drift within the smithy-runtime track should fail");
+        assert_eq!(
+            "Crate named `aws-smithy-http` is at version `0.36.0`, which is not semver-compatible with its track's baseline version `0.35.1`",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn pre_validate_with_explicit_crate_track() {
+        // Crates can be explicitly pinned to a shared track, e.g. a family of
+        // generated service crates that are meant to be released together even
+        // though neither is `aws-smithy-*` nor `aws-config` itself.
+        let policy = policy(
+            &[("dynamodb-family", "aws-sdk-dynamodb")],
+            &[("aws-sdk-dynamodbstreams", "dynamodb-family")],
+        );
+        validate_before_fixes_with_policy(
+            &versions(&[
+                ("aws-sdk-dynamodb", "0.5.1"),
+                ("aws-sdk-dynamodbstreams", "0.5.1"),
+            ]),
+            &policy,
+        )
+        .expect("crates pinned to the same explicit track must stay compatible");
+
+        let err = validate_before_fixes_with_policy(
+            &versions(&[
+                ("aws-sdk-dynamodb", "0.5.1"),
+                ("aws-sdk-dynamodbstreams", "0.6.0"),
+            ]),
+            &policy,
+        )
+        .expect_err("minor version drift within a 0.x explicit track should fail");
+        assert_eq!(
+            "Crate named `aws-sdk-dynamodbstreams` is at version `0.6.0`, which is not semver-compatible with its track's baseline version `0.5.1`",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn pre_validate_rejects_crate_behind_its_track_baseline() {
+        // Sharing a major (or, below 1.0.0, a minor) isn't enough -- a crate that has
+        // fallen behind its track's baseline doesn't satisfy a caret requirement on it.
+        let policy = policy(&[("sdk", "aws-config")], &[("aws-types", "sdk")]);
+        let err = validate_before_fixes_with_policy(
+            &versions(&[("aws-config", "1.5.2"), ("aws-types", "1.0.0")]),
+            &policy,
+        )
+        .expect_err("aws-types is behind aws-config's baseline despite matching major");
+        assert_eq!(
+            "Crate named `aws-types` is at version `1.0.0`, which is not semver-compatible with its track's baseline version `1.5.2`",
+            format!("{}", err)
+        );
+
+        let err = validate_before_fixes_with_policy(
+            &versions(&[("aws-config", "0.5.9"), ("aws-types", "0.5.0")]),
+            &policy,
+        )
+        .expect_err("aws-types is behind aws-config's baseline despite matching minor");
+        assert_eq!(
+            "Crate named `aws-types` is at version `0.5.0`, which is not semver-compatible with its track's baseline version `0.5.9`",
+            format!("{}", err)
+        );
+    }
+
+    #[test]
+    fn load_version_policy_round_trips_toml() {
+        let path = std::env::temp_dir().join(format!(
+            "smithy-rs-version-policy-test-{}-{}.toml",
+            std::process::id(),
+            "load_version_policy_round_trips_toml"
+        ));
+        std::fs::write(
+            &path,
+            r#"
+                [baselines]
+                smithy-runtime = "aws-smithy-types"
+                sdk = "aws-config"
+
+                [tracks]
+                aws-types = "sdk"
+            "#,
+        )
+        .expect("failed to write test version policy manifest");
+
+        let policy = VersionPolicy::load(&path);
+        std::fs::remove_file(&path).ok();
+        let policy = policy.expect("failed to load version policy");
+
+        assert_eq!(
+            Some(&"aws-smithy-types".to_string()),
+            policy.baselines.get("smithy-runtime")
+        );
+        assert_eq!(Some(&"aws-config".to_string()), policy.baselines.get("sdk"));
+        assert_eq!(Some(&"sdk".to_string()), policy.tracks.get("aws-types"));
+    }
 }
\ No newline at end of file